@@ -0,0 +1,417 @@
+use apollo_compiler::collections::IndexMap;
+use apollo_compiler::schema::Component;
+use apollo_compiler::schema::EnumType;
+use apollo_compiler::schema::ExtendedType;
+use apollo_compiler::schema::FieldDefinition;
+use apollo_compiler::schema::InputValueDefinition;
+use apollo_compiler::schema::UnionType;
+use apollo_compiler::Name;
+
+use crate::composition::compose;
+use crate::error::CompositionError;
+use crate::subgraph::typestate::Initial;
+use crate::subgraph::typestate::Subgraph;
+use crate::supergraph::Satisfiable;
+use crate::supergraph::Supergraph;
+
+/// How risky a change between two supergraphs is for clients already querying the old one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChangeSeverity {
+    /// Cannot break an existing client, e.g. an added type or an added optional argument.
+    Safe,
+    /// Unlikely to break existing clients, but worth a human's attention, e.g. an added enum
+    /// value or union member, or a changed input field type.
+    Dangerous,
+    /// Can break an existing client, e.g. a removed field or an output field loosened from
+    /// non-null to nullable.
+    Breaking,
+}
+
+/// A single difference between a baseline supergraph and a newly composed one.
+#[derive(Clone, Debug)]
+pub struct SchemaChange {
+    /// The type, or `Type.field`, the change applies to.
+    pub path: String,
+    pub severity: ChangeSeverity,
+    pub description: String,
+}
+
+/// The successful result of [`check_composition`]: a classified diff against the baseline
+/// supergraph (empty when no baseline was supplied). Composition errors are returned as the
+/// `Err` of [`check_composition`] instead.
+#[derive(Clone, Debug, Default)]
+pub struct CompositionCheckResult {
+    pub changes: Vec<SchemaChange>,
+}
+
+impl CompositionCheckResult {
+    /// Whether any change in this result is classified as [`ChangeSeverity::Breaking`].
+    pub fn has_breaking_changes(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|change| change.severity == ChangeSeverity::Breaking)
+    }
+}
+
+/// Composes `subgraphs` and, if `baseline` is provided, classifies how the resulting supergraph
+/// differs from it. This lets callers gate merges in CI on whether a proposed set of subgraphs
+/// introduces breaking changes, without requiring a remote registry.
+pub fn check_composition(
+    subgraphs: Vec<Subgraph<Initial>>,
+    baseline: Option<Supergraph<Satisfiable>>,
+) -> Result<CompositionCheckResult, Vec<CompositionError>> {
+    let supergraph = compose(subgraphs)?;
+
+    let changes = match &baseline {
+        Some(baseline) => diff_supergraphs(baseline, &supergraph),
+        None => Vec::new(),
+    };
+
+    Ok(CompositionCheckResult { changes })
+}
+
+fn diff_supergraphs(
+    before: &Supergraph<Satisfiable>,
+    after: &Supergraph<Satisfiable>,
+) -> Vec<SchemaChange> {
+    let before_types = &before.schema().types;
+    let after_types = &after.schema().types;
+
+    let mut changes = Vec::new();
+    for (type_name, before_type) in before_types {
+        match after_types.get(type_name) {
+            None => changes.push(SchemaChange {
+                path: type_name.to_string(),
+                severity: ChangeSeverity::Breaking,
+                description: format!("Type `{type_name}` was removed"),
+            }),
+            Some(after_type) => changes.extend(diff_type(type_name, before_type, after_type)),
+        }
+    }
+    for type_name in after_types.keys() {
+        if !before_types.contains_key(type_name) {
+            changes.push(SchemaChange {
+                path: type_name.to_string(),
+                severity: ChangeSeverity::Safe,
+                description: format!("Type `{type_name}` was added"),
+            });
+        }
+    }
+
+    changes
+}
+
+fn diff_type(type_name: &str, before: &ExtendedType, after: &ExtendedType) -> Vec<SchemaChange> {
+    match (before, after) {
+        (ExtendedType::Object(before), ExtendedType::Object(after)) => {
+            diff_fields(type_name, &before.fields, &after.fields)
+        }
+        (ExtendedType::Interface(before), ExtendedType::Interface(after)) => {
+            diff_fields(type_name, &before.fields, &after.fields)
+        }
+        (ExtendedType::InputObject(before), ExtendedType::InputObject(after)) => {
+            diff_input_fields(type_name, &before.fields, &after.fields)
+        }
+        (ExtendedType::Enum(before), ExtendedType::Enum(after)) => {
+            diff_enum_values(type_name, before, after)
+        }
+        (ExtendedType::Union(before), ExtendedType::Union(after)) => {
+            diff_union_members(type_name, before, after)
+        }
+        (ExtendedType::Scalar(_), ExtendedType::Scalar(_)) => Vec::new(),
+        (before, after) => vec![SchemaChange {
+            path: type_name.to_string(),
+            severity: ChangeSeverity::Breaking,
+            description: format!(
+                "Type `{type_name}` changed kind from {} to {}",
+                type_kind_name(before),
+                type_kind_name(after)
+            ),
+        }],
+    }
+}
+
+fn type_kind_name(extended_type: &ExtendedType) -> &'static str {
+    match extended_type {
+        ExtendedType::Object(_) => "object",
+        ExtendedType::Interface(_) => "interface",
+        ExtendedType::Union(_) => "union",
+        ExtendedType::Enum(_) => "enum",
+        ExtendedType::InputObject(_) => "input object",
+        ExtendedType::Scalar(_) => "scalar",
+    }
+}
+
+fn diff_fields(
+    type_name: &str,
+    before: &IndexMap<Name, Component<FieldDefinition>>,
+    after: &IndexMap<Name, Component<FieldDefinition>>,
+) -> Vec<SchemaChange> {
+    let mut changes = Vec::new();
+    for (field_name, before_field) in before {
+        let path = format!("{type_name}.{field_name}");
+        match after.get(field_name) {
+            None => changes.push(SchemaChange {
+                path,
+                severity: ChangeSeverity::Breaking,
+                description: format!("Field `{field_name}` was removed"),
+            }),
+            Some(after_field) => changes.extend(diff_field(&path, before_field, after_field)),
+        }
+    }
+    for field_name in after.keys() {
+        if !before.contains_key(field_name) {
+            changes.push(SchemaChange {
+                path: format!("{type_name}.{field_name}"),
+                severity: ChangeSeverity::Safe,
+                description: format!("Field `{field_name}` was added"),
+            });
+        }
+    }
+
+    changes
+}
+
+fn diff_input_fields(
+    type_name: &str,
+    before: &IndexMap<Name, Component<InputValueDefinition>>,
+    after: &IndexMap<Name, Component<InputValueDefinition>>,
+) -> Vec<SchemaChange> {
+    let mut changes = Vec::new();
+    for (field_name, before_field) in before {
+        let path = format!("{type_name}.{field_name}");
+        match after.get(field_name) {
+            None => changes.push(SchemaChange {
+                path,
+                severity: ChangeSeverity::Breaking,
+                description: format!("Input field `{field_name}` was removed"),
+            }),
+            Some(after_field) if before_field.ty != after_field.ty => changes.push(SchemaChange {
+                path,
+                severity: ChangeSeverity::Dangerous,
+                description: format!(
+                    "Input field type changed from `{}` to `{}`",
+                    before_field.ty, after_field.ty
+                ),
+            }),
+            Some(_) => {}
+        }
+    }
+    for (field_name, after_field) in after {
+        if before.contains_key(field_name) {
+            continue;
+        }
+        // An added input field is only safe for existing clients if it's optional; a required
+        // one (non-null, no default) means every existing caller is now missing an argument.
+        let severity = if after_field.ty.is_non_null() && after_field.default_value.is_none() {
+            ChangeSeverity::Breaking
+        } else {
+            ChangeSeverity::Safe
+        };
+        changes.push(SchemaChange {
+            path: format!("{type_name}.{field_name}"),
+            severity,
+            description: format!("Input field `{field_name}` was added"),
+        });
+    }
+
+    changes
+}
+
+fn diff_enum_values(type_name: &str, before: &EnumType, after: &EnumType) -> Vec<SchemaChange> {
+    let mut changes = Vec::new();
+    for value_name in before.values.keys() {
+        if !after.values.contains_key(value_name) {
+            changes.push(SchemaChange {
+                path: format!("{type_name}.{value_name}"),
+                severity: ChangeSeverity::Breaking,
+                description: format!("Enum value `{value_name}` was removed"),
+            });
+        }
+    }
+    for value_name in after.values.keys() {
+        if !before.values.contains_key(value_name) {
+            // A client with an exhaustive switch over the old values could mishandle this, so
+            // it's worth a human's attention even though it isn't a hard break.
+            changes.push(SchemaChange {
+                path: format!("{type_name}.{value_name}"),
+                severity: ChangeSeverity::Dangerous,
+                description: format!("Enum value `{value_name}` was added"),
+            });
+        }
+    }
+
+    changes
+}
+
+fn diff_union_members(type_name: &str, before: &UnionType, after: &UnionType) -> Vec<SchemaChange> {
+    let mut changes = Vec::new();
+    for member in &before.members {
+        if !after.members.contains(member) {
+            changes.push(SchemaChange {
+                path: format!("{type_name}.{member}"),
+                severity: ChangeSeverity::Breaking,
+                description: format!("Union member `{member}` was removed"),
+            });
+        }
+    }
+    for member in &after.members {
+        if !before.members.contains(member) {
+            changes.push(SchemaChange {
+                path: format!("{type_name}.{member}"),
+                severity: ChangeSeverity::Dangerous,
+                description: format!("Union member `{member}` was added"),
+            });
+        }
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use apollo_compiler::Schema;
+
+    use super::*;
+
+    fn parse(sdl: &str) -> Schema {
+        Schema::parse(sdl, "test.graphql").expect("schema parse failed")
+    }
+
+    fn diff(type_name: &str, before: &str, after: &str) -> Vec<SchemaChange> {
+        let before = parse(before);
+        let after = parse(after);
+        diff_type(
+            type_name,
+            before.types.get(type_name).expect("missing before type"),
+            after.types.get(type_name).expect("missing after type"),
+        )
+    }
+
+    #[test]
+    fn field_removal_is_breaking() {
+        let changes = diff("Foo", "type Foo { a: String b: String }", "type Foo { a: String }");
+        assert!(changes
+            .iter()
+            .any(|c| c.severity == ChangeSeverity::Breaking && c.description.contains("removed")));
+    }
+
+    #[test]
+    fn field_addition_is_safe() {
+        let changes = diff("Foo", "type Foo { a: String }", "type Foo { a: String b: String }");
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].severity, ChangeSeverity::Safe);
+    }
+
+    #[test]
+    fn narrowing_output_field_to_non_null_is_safe() {
+        let changes = diff("Foo", "type Foo { a: String }", "type Foo { a: String! }");
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].severity, ChangeSeverity::Safe);
+    }
+
+    #[test]
+    fn loosening_output_field_to_nullable_is_breaking() {
+        let changes = diff("Foo", "type Foo { a: String! }", "type Foo { a: String }");
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].severity, ChangeSeverity::Breaking);
+    }
+
+    #[test]
+    fn type_kind_change_is_breaking() {
+        let changes = diff("Foo", "type Foo { a: String }", "interface Foo { a: String }");
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].severity, ChangeSeverity::Breaking);
+    }
+
+    #[test]
+    fn removed_enum_value_is_breaking() {
+        let changes = diff("Foo", "enum Foo { A B }", "enum Foo { A }");
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].severity, ChangeSeverity::Breaking);
+    }
+
+    #[test]
+    fn required_input_field_addition_is_breaking() {
+        let changes = diff(
+            "Foo",
+            "input Foo { a: String }",
+            "input Foo { a: String b: String! }",
+        );
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].severity, ChangeSeverity::Breaking);
+    }
+
+    #[test]
+    fn optional_input_field_addition_is_safe() {
+        let changes = diff(
+            "Foo",
+            "input Foo { a: String }",
+            "input Foo { a: String b: String }",
+        );
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].severity, ChangeSeverity::Safe);
+    }
+}
+
+fn diff_field(path: &str, before: &FieldDefinition, after: &FieldDefinition) -> Vec<SchemaChange> {
+    let mut changes = Vec::new();
+
+    if before.ty != after.ty {
+        // These are output (response) field types: tightening nullable -> non-null can only
+        // give clients more than they asked for, while loosening non-null -> nullable means a
+        // client that assumed a value is always present may now see `null`.
+        let severity = if !before.ty.is_non_null()
+            && after.ty.is_non_null()
+            && after.ty.clone().nullable() == before.ty
+        {
+            ChangeSeverity::Safe
+        } else if before.ty.is_non_null() && !after.ty.is_non_null() {
+            ChangeSeverity::Breaking
+        } else {
+            ChangeSeverity::Dangerous
+        };
+        changes.push(SchemaChange {
+            path: path.to_string(),
+            severity,
+            description: format!(
+                "Field type changed from `{}` to `{}`",
+                before.ty, after.ty
+            ),
+        });
+    }
+
+    for after_arg in &after.arguments {
+        if !before
+            .arguments
+            .iter()
+            .any(|before_arg| before_arg.name == after_arg.name)
+        {
+            let severity = if after_arg.ty.is_non_null() && after_arg.default_value.is_none() {
+                ChangeSeverity::Breaking
+            } else {
+                ChangeSeverity::Safe
+            };
+            changes.push(SchemaChange {
+                path: format!("{path}({}:)", after_arg.name),
+                severity,
+                description: format!("Argument `{}` was added", after_arg.name),
+            });
+        }
+    }
+    for before_arg in &before.arguments {
+        if !after
+            .arguments
+            .iter()
+            .any(|after_arg| after_arg.name == before_arg.name)
+        {
+            changes.push(SchemaChange {
+                path: format!("{path}({}:)", before_arg.name),
+                severity: ChangeSeverity::Breaking,
+                description: format!("Argument `{}` was removed", before_arg.name),
+            });
+        }
+    }
+
+    changes
+}
@@ -0,0 +1,165 @@
+use apollo_compiler::ast::Argument;
+use apollo_compiler::ast::Directive;
+use apollo_compiler::ast::Value;
+use apollo_compiler::name;
+use apollo_compiler::Node;
+use apollo_compiler::Schema;
+
+use crate::error::CompositionError;
+use crate::link::spec::Version;
+use crate::subgraph::typestate::Expanded;
+use crate::subgraph::typestate::Subgraph;
+
+const FEDERATION_SPEC_PREFIX: &str = "https://specs.apollo.dev/federation/v";
+
+/// The latest federation spec version this composition implementation targets when no subgraph
+/// declares one and no explicit version is supplied.
+pub(crate) const LATEST_FEDERATION_VERSION: Version = Version { major: 2, minor: 9 };
+
+/// Resolves the federation spec version the composed supergraph should target.
+///
+/// `requested` (e.g. supplied by a registry) always takes precedence. Otherwise every expanded
+/// subgraph is scanned for a federation `@link`; if none declare one, composition defaults to
+/// [`LATEST_FEDERATION_VERSION`]. Subgraphs that declare mutually incompatible major versions are
+/// rejected.
+pub(crate) fn resolve_federation_version(
+    subgraphs: &[Subgraph<Expanded>],
+    requested: Option<Version>,
+) -> Result<Version, CompositionError> {
+    if let Some(version) = requested {
+        return Ok(version);
+    }
+
+    let mut resolved: Option<(Version, &str)> = None;
+    for subgraph in subgraphs {
+        let Some(version) = federation_link_version(subgraph) else {
+            continue;
+        };
+        match resolved {
+            None => resolved = Some((version, subgraph.name())),
+            Some((existing, existing_name)) if existing.major != version.major => {
+                return Err(CompositionError::TypeDefinitionInvalid {
+                    message: format!(
+                        "subgraph \"{}\" declares federation v{version} which is incompatible \
+                         with v{existing} declared by subgraph \"{existing_name}\"",
+                        subgraph.name(),
+                    ),
+                });
+            }
+            // Same major: take the higher minor, so a subgraph relying on newer features
+            // doesn't get silently downgraded to whichever compatible version we saw first.
+            Some((existing, _)) if version.minor > existing.minor => {
+                resolved = Some((version, subgraph.name()));
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok(resolved.map(|(version, _)| version).unwrap_or(LATEST_FEDERATION_VERSION))
+}
+
+fn federation_link_version(subgraph: &Subgraph<Expanded>) -> Option<Version> {
+    subgraph
+        .schema()
+        .schema()
+        .schema_definition
+        .directives
+        .iter()
+        .filter(|directive| directive.name == "link")
+        .find_map(|directive| {
+            let url = directive.argument_by_name("url")?.as_str()?;
+            let version_str = url.strip_prefix(FEDERATION_SPEC_PREFIX)?;
+            Version::parse(version_str).ok()
+        })
+}
+
+/// Records the resolved federation version on `schema`'s `@link` set, updating an existing
+/// federation link in place or adding one if none of the merged subgraphs carried one forward.
+pub(crate) fn set_federation_link_version(schema: &mut Schema, version: Version) {
+    let url = format!("{FEDERATION_SPEC_PREFIX}{version}");
+    let schema_definition = schema.schema_definition.make_mut();
+
+    let existing_link = schema_definition
+        .directives
+        .iter_mut()
+        .find(|directive| directive.name == "link" && is_federation_link(directive));
+
+    if let Some(directive) = existing_link {
+        let directive = directive.make_mut();
+        directive.arguments.retain(|arg| arg.name != "url");
+        directive.arguments.push(Node::new(Argument {
+            name: name!("url"),
+            value: Node::new(Value::String(url)),
+        }));
+        return;
+    }
+
+    schema_definition.directives.push(Node::new(Directive {
+        name: name!("link"),
+        arguments: vec![Node::new(Argument {
+            name: name!("url"),
+            value: Node::new(Value::String(url)),
+        })],
+    }));
+}
+
+fn is_federation_link(directive: &Node<Directive>) -> bool {
+    directive
+        .argument_by_name("url")
+        .and_then(|value| value.as_str())
+        .is_some_and(|url| url.starts_with(FEDERATION_SPEC_PREFIX))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::subgraph::typestate::Initial;
+    use crate::subgraph::typestate::Subgraph;
+
+    use super::*;
+
+    fn expanded_subgraph(name: &str, federation_version: &str) -> Subgraph<Expanded> {
+        let sdl = format!(
+            r#"
+            extend schema
+              @link(url: "https://specs.apollo.dev/federation/v{federation_version}", import: ["@key"])
+
+            type Query {{ hello: String }}
+            "#
+        );
+        let schema = Schema::parse(sdl, format!("{name}.graphql")).expect("schema parse failed");
+        Subgraph::<Initial>::new(name, "http://localhost", schema)
+            .expand_links()
+            .expect("expand_links failed")
+    }
+
+    #[test]
+    fn requested_version_always_wins() {
+        let subgraphs = vec![expanded_subgraph("a", "2.3")];
+        let requested = Version { major: 2, minor: 1 };
+        let resolved = resolve_federation_version(&subgraphs, Some(requested)).unwrap();
+        assert_eq!(resolved, requested);
+    }
+
+    #[test]
+    fn defaults_to_latest_when_nothing_declared_or_requested() {
+        let resolved = resolve_federation_version(&[], None).unwrap();
+        assert_eq!(resolved, LATEST_FEDERATION_VERSION);
+    }
+
+    #[test]
+    fn picks_max_compatible_minor() {
+        let subgraphs = vec![expanded_subgraph("a", "2.3"), expanded_subgraph("b", "2.9")];
+        let resolved = resolve_federation_version(&subgraphs, None).unwrap();
+        assert_eq!(resolved, Version { major: 2, minor: 9 });
+    }
+
+    #[test]
+    fn rejects_incompatible_major_versions() {
+        let subgraphs = vec![expanded_subgraph("a", "1.1"), expanded_subgraph("b", "2.3")];
+        let result = resolve_federation_version(&subgraphs, None);
+        assert!(matches!(
+            result,
+            Err(CompositionError::TypeDefinitionInvalid { .. })
+        ));
+    }
+}
@@ -1,12 +1,27 @@
+mod check;
+mod federation_version;
+mod options;
+mod resolvability;
 mod satisfiability;
 
 use std::vec;
 
-use apollo_compiler::schema::ExtendedType;
 use apollo_compiler::validation::Valid;
 
+pub use crate::composition::check::check_composition;
+pub use crate::composition::check::ChangeSeverity;
+pub use crate::composition::check::CompositionCheckResult;
+pub use crate::composition::check::SchemaChange;
+use crate::composition::federation_version::resolve_federation_version;
+use crate::composition::federation_version::set_federation_link_version;
+use crate::composition::federation_version::LATEST_FEDERATION_VERSION;
+use crate::composition::resolvability::check_field_resolvability;
+pub use crate::composition::options::CompositionOptions;
+pub use crate::composition::options::PrintOptions;
+use crate::composition::options::apply_print_options;
 pub use crate::composition::satisfiability::validate_satisfiability;
 use crate::error::CompositionError;
+use crate::link::spec::Version;
 use crate::merge::merge_federation_subgraphs;
 use crate::merge::MergeFailure;
 use crate::merge::MergeSuccess;
@@ -26,13 +41,29 @@ use crate::ValidFederationSubgraphs;
 
 pub fn compose(
     subgraphs: Vec<Subgraph<Initial>>,
+) -> Result<Supergraph<Satisfiable>, Vec<CompositionError>> {
+    compose_with_options(subgraphs, CompositionOptions::default())
+}
+
+/// Same as [`compose`], but lets the caller control composition behavior that doesn't have a
+/// single universally-correct default, e.g. how the resulting supergraph SDL is ordered.
+pub fn compose_with_options(
+    subgraphs: Vec<Subgraph<Initial>>,
+    options: CompositionOptions,
 ) -> Result<Supergraph<Satisfiable>, Vec<CompositionError>> {
     let expanded_subgraphs = expand_subgraphs(subgraphs)?;
+    let federation_version =
+        resolve_federation_version(&expanded_subgraphs, options.federation_version)
+            .map_err(|e| vec![e])?;
     let upgraded_subgraphs = upgrade_subgraphs_if_necessary(expanded_subgraphs)?;
     let validated_subgraphs = validate_subgraphs(upgraded_subgraphs)?;
 
     pre_merge_validations(&validated_subgraphs)?;
-    let supergraph = merge_subgraphs(validated_subgraphs)?;
+    let supergraph = merge_subgraphs_with_options(
+        validated_subgraphs,
+        &options.print_options,
+        federation_version,
+    )?;
     post_merge_validations(&supergraph)?;
 
     validate_satisfiability(supergraph)
@@ -78,14 +109,15 @@ pub fn pre_merge_validations(
     subgraphs: &[Subgraph<Validated>],
 ) -> Result<(), Vec<CompositionError>> {
     let mut errors = Vec::new();
-    
-    // Track subgraphs by their schema string representation
-    let mut seen_schemas = std::collections::HashSet::new();
+
+    // Two legitimately distinct subgraphs can share identical SDL (e.g. thin wrappers around the
+    // same underlying service), but two subgraphs can't share a name: the name is the merge key,
+    // so a collision there silently drops one of them.
+    let mut seen_names = std::collections::HashSet::new();
     for subgraph in subgraphs {
-        let schema_str = subgraph.schema_string();
-        if !seen_schemas.insert(schema_str.clone()) {
+        if !seen_names.insert(subgraph.name()) {
             errors.push(CompositionError::TypeDefinitionInvalid {
-                message: "Duplicate subgraph schema detected".to_string(),
+                message: format!("Duplicate subgraph name detected: \"{}\"", subgraph.name()),
             });
         }
     }
@@ -94,10 +126,10 @@ pub fn pre_merge_validations(
     for subgraph in subgraphs {
         let raw_schema = subgraph.schema().schema().clone();
         match ValidFederationSchema::new(Valid::assume_valid(raw_schema)) {
-            Ok(_) => (), 
+            Ok(_) => (),
             Err(e) => {
                 errors.push(CompositionError::SubgraphError {
-                    subgraph: "Subgraph".to_string(),
+                    subgraph: subgraph.name().to_string(),
                     error: e,
                 });
             }
@@ -114,28 +146,51 @@ pub fn pre_merge_validations(
 
 pub fn merge_subgraphs(
     subgraphs: Vec<Subgraph<Validated>>,
+) -> Result<Supergraph<Merged>, Vec<CompositionError>> {
+    merge_subgraphs_with_options(subgraphs, &PrintOptions::default(), LATEST_FEDERATION_VERSION)
+}
+
+/// Same as [`merge_subgraphs`], but applies `print_options` to the merged schema and records
+/// `federation_version` on its `@link` set before it's wrapped up as a [`Supergraph`].
+pub fn merge_subgraphs_with_options(
+    subgraphs: Vec<Subgraph<Validated>>,
+    print_options: &PrintOptions,
+    federation_version: Version,
 ) -> Result<Supergraph<Merged>, Vec<CompositionError>> {
     use std::collections::BTreeMap;
     use std::sync::Arc;
 
-    // Convert to federation subgraphs format expected by the merger
+    // Convert to federation subgraphs format expected by the merger, keyed by each subgraph's
+    // actual name so routing information survives the merge. `compose` already rejects duplicate
+    // names via `pre_merge_validations`, but this function is also called directly (by
+    // `merge_subgraphs` and by callers that skip that step), so a collision is checked again here
+    // rather than trusted to have been caught upstream — an unchecked `insert` would otherwise
+    // silently collapse two subgraphs into one.
     let mut subgraphs_map = BTreeMap::new();
+    let mut seen_names = std::collections::HashSet::new();
     for subgraph in subgraphs {
+        let name = subgraph.name().to_string();
+        if !seen_names.insert(name.clone()) {
+            return Err(vec![CompositionError::TypeDefinitionInvalid {
+                message: format!("Duplicate subgraph name detected: \"{name}\""),
+            }]);
+        }
+        let url = subgraph.url().unwrap_or_default().to_string();
         let schema = subgraph.schema().schema().clone();
         match ValidFederationSchema::new(Valid::assume_valid(schema)) {
             Ok(valid_schema) => {
                 subgraphs_map.insert(
-                    Arc::from("Subgraph"), 
+                    Arc::from(name.as_str()),
                     ValidFederationSubgraph {
-                        name: "Subgraph".to_string(),
-                        url: "".to_string(),
-                        schema: valid_schema, 
+                        name,
+                        url,
+                        schema: valid_schema,
                     },
                 );
             }
             Err(e) => {
                 return Err(vec![CompositionError::SubgraphError {
-                    subgraph: "Subgraph".to_string(),
+                    subgraph: name,
                     error: e,
                 }]);
             }
@@ -150,7 +205,10 @@ pub fn merge_subgraphs(
     // Perform the actual merge
     match merge_federation_subgraphs(subgraphs_for_merge) {
         Ok(MergeSuccess { schema, .. }) => {
-            Ok(Supergraph::<Merged>::new(schema))
+            let mut schema = schema.into_inner();
+            set_federation_link_version(&mut schema, federation_version);
+            apply_print_options(&mut schema, print_options);
+            Ok(Supergraph::<Merged>::new(Valid::assume_valid(schema)))
         }
         Err(MergeFailure { errors, .. }) => Err(errors
             .into_iter()
@@ -203,7 +261,7 @@ pub fn post_merge_validations(
     };
 
     // Build query graph
-    let _query_graph = match build_query_graph::build_federated_query_graph(
+    let query_graph = match build_query_graph::build_federated_query_graph(
         federation_schema.clone(),
         federation_schema.clone(),
         None,
@@ -218,21 +276,9 @@ pub fn post_merge_validations(
         }
     };
 
-    // Check field existence using the correct method name
-    for (type_name, type_def) in &schema.types {
-        if let ExtendedType::Object(obj) = type_def {
-            for (field_name, _) in &obj.fields {
-                if !obj.fields.contains_key(field_name) {
-                    errors.push(CompositionError::SatisfiabilityError {
-                        message: format!(
-                            "Field '{}.{}' cannot be resolved across subgraphs",
-                            type_name, field_name
-                        ),
-                    });
-                }
-            }
-        }
-    }
+    // Field resolvability: every declared field must have a path through the federated query
+    // graph that can resolve it, given the `@key`/`@requires` edges contributed by subgraphs.
+    errors.extend(check_field_resolvability(&schema, &query_graph));
 
 
     if errors.is_empty() {
@@ -0,0 +1,207 @@
+use apollo_compiler::ast::DirectiveList;
+use apollo_compiler::schema::ExtendedType;
+use apollo_compiler::Schema;
+
+use crate::link::spec::Version;
+
+/// Controls how a composed supergraph's SDL is ordered when printed.
+///
+/// The default preserves whatever ordering the merge produced (generally the order in which
+/// subgraphs contributed each definition), which is usually what a human editing a schema by
+/// hand would expect. `OrderPrintedDefinitions` instead sorts every element lexicographically,
+/// which is useful for diffable, reproducible supergraph artifacts in CI.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PrintOptions {
+    /// Keep the insertion order produced by composition.
+    #[default]
+    PreserveInsertionOrder,
+    /// Sort type definitions, their members, and directive definitions/applications
+    /// alphabetically before printing. Root operation types are always printed first.
+    OrderPrintedDefinitions,
+}
+
+/// Options accepted by [`crate::composition::compose_with_options`].
+#[derive(Clone, Debug, Default)]
+pub struct CompositionOptions {
+    /// How the resulting supergraph SDL should be ordered when printed.
+    pub print_options: PrintOptions,
+    /// The federation spec version the supergraph should target. Takes precedence over any
+    /// version inferred from the subgraphs' own `@link` directives (e.g. so a registry can
+    /// override the default without warnings). Defaults to the version inferred from the
+    /// subgraphs, or the latest supported version if none declare one.
+    pub federation_version: Option<Version>,
+}
+
+/// Sorts `schema`'s type map and the member collections of each type definition in place,
+/// according to `options`. A no-op when `options` is `PreserveInsertionOrder`.
+///
+/// When sorting, root operation types (`Query`/`Mutation`/`Subscription`) are pinned first, in
+/// that order, ahead of the alphabetically sorted remaining types.
+pub(crate) fn apply_print_options(schema: &mut Schema, options: &PrintOptions) {
+    if *options != PrintOptions::OrderPrintedDefinitions {
+        return;
+    }
+
+    let root_names: Vec<String> = [
+        schema.schema_definition.query.as_ref(),
+        schema.schema_definition.mutation.as_ref(),
+        schema.schema_definition.subscription.as_ref(),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|name| name.to_string())
+    .collect();
+
+    let root_rank = |name: &str| -> usize {
+        root_names
+            .iter()
+            .position(|root| root == name)
+            .unwrap_or(root_names.len())
+    };
+
+    schema
+        .types
+        .sort_by(|k1, _, k2, _| (root_rank(k1), k1).cmp(&(root_rank(k2), k2)));
+
+    for extended_type in schema.types.values_mut() {
+        sort_type_members(extended_type);
+    }
+
+    schema.directive_definitions.sort_keys();
+    sort_directives(&mut schema.schema_definition.make_mut().directives);
+}
+
+fn sort_type_members(extended_type: &mut ExtendedType) {
+    match extended_type {
+        ExtendedType::Object(object) => {
+            let object = object.make_mut();
+            object.fields.sort_keys();
+            sort_directives(&mut object.directives);
+            for field in object.fields.values_mut() {
+                sort_directives(&mut field.make_mut().directives);
+            }
+        }
+        ExtendedType::Interface(interface) => {
+            let interface = interface.make_mut();
+            interface.fields.sort_keys();
+            sort_directives(&mut interface.directives);
+            for field in interface.fields.values_mut() {
+                sort_directives(&mut field.make_mut().directives);
+            }
+        }
+        ExtendedType::InputObject(input_object) => {
+            let input_object = input_object.make_mut();
+            input_object.fields.sort_keys();
+            sort_directives(&mut input_object.directives);
+            for field in input_object.fields.values_mut() {
+                sort_directives(&mut field.make_mut().directives);
+            }
+        }
+        ExtendedType::Enum(enum_type) => {
+            let enum_type = enum_type.make_mut();
+            enum_type.values.sort_keys();
+            sort_directives(&mut enum_type.directives);
+            for value in enum_type.values.values_mut() {
+                sort_directives(&mut value.make_mut().directives);
+            }
+        }
+        ExtendedType::Union(union_type) => {
+            let union_type = union_type.make_mut();
+            union_type.members.sort();
+            sort_directives(&mut union_type.directives);
+        }
+        ExtendedType::Scalar(scalar) => {
+            sort_directives(&mut scalar.make_mut().directives);
+        }
+    }
+}
+
+/// Sorts directive *applications* (e.g. `@tag(name: "x")` on a field) alphabetically by name, in
+/// place. Relative order among multiple applications of the same directive is preserved.
+fn sort_directives(directives: &mut DirectiveList) {
+    directives.sort_by(|a, b| a.name.cmp(&b.name));
+}
+
+#[cfg(test)]
+mod tests {
+    use apollo_compiler::validation::Valid;
+
+    use super::*;
+
+    fn apply(sdl: &str, options: PrintOptions) -> Valid<Schema> {
+        let schema = Schema::parse(sdl, "test.graphql").expect("schema parse failed");
+        let mut schema = Valid::assume_valid(schema).into_inner();
+        apply_print_options(&mut schema, &options);
+        Valid::assume_valid(schema)
+    }
+
+    #[test]
+    fn preserve_insertion_order_is_a_no_op() {
+        let sdl = "type Query { b: String a: String }";
+        let before = apply(sdl, PrintOptions::PreserveInsertionOrder);
+        let field_names: Vec<&str> = before
+            .types
+            .get("Query")
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .fields
+            .keys()
+            .map(|name| name.as_str())
+            .collect();
+        assert_eq!(field_names, vec!["b", "a"]);
+    }
+
+    #[test]
+    fn order_printed_definitions_sorts_types_and_fields() {
+        let sdl = "type Zeta { z: String a: String } type Query { q: Zeta }";
+        let schema = apply(sdl, PrintOptions::OrderPrintedDefinitions);
+
+        let type_names: Vec<&str> = schema
+            .types
+            .iter()
+            .filter(|(_, ty)| !ty.is_built_in())
+            .map(|(name, _)| name.as_str())
+            .collect();
+        // Built-in scalars and introspection types are left out. "Query" also sorts before
+        // "Zeta" alphabetically, so this order does not isolate the root pinning.
+        assert_eq!(type_names, vec!["Query", "Zeta"]);
+
+        let field_names: Vec<&str> = schema
+            .types
+            .get("Zeta")
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .fields
+            .keys()
+            .map(|name| name.as_str())
+            .collect();
+        assert_eq!(field_names, vec!["a", "z"]);
+    }
+
+    #[test]
+    fn order_printed_definitions_sorts_directive_applications() {
+        let sdl = r#"
+            directive @b on FIELD_DEFINITION
+            directive @a on FIELD_DEFINITION
+            type Query { f: String @b @a }
+        "#;
+        let schema = apply(sdl, PrintOptions::OrderPrintedDefinitions);
+
+        let directive_names: Vec<&str> = schema
+            .types
+            .get("Query")
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .fields
+            .get("f")
+            .unwrap()
+            .directives
+            .iter()
+            .map(|directive| directive.name.as_str())
+            .collect();
+        assert_eq!(directive_names, vec!["a", "b"]);
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use apollo_compiler::schema::ExtendedType;
+use apollo_compiler::Name;
+use apollo_compiler::Schema;
+use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef;
+
+use crate::error::CompositionError;
+use crate::query_graph::QueryGraph;
+use crate::query_graph::QueryGraphEdgeTransition;
+use crate::query_graph::QueryGraphNodeType;
+
+/// Walks `query_graph` and confirms every object/interface field declared in `schema` has at
+/// least one path, reachable from a federated root, that can resolve it — following
+/// `@key`/`@requires` edges across subgraph boundaries, not just a direct field edge on the
+/// declaring type. A field with no such path is declared on the supergraph but unreachable,
+/// which is reported as a `CompositionError::SatisfiabilityError` rather than silently accepted.
+pub(crate) fn check_field_resolvability(
+    schema: &Schema,
+    query_graph: &QueryGraph,
+) -> Vec<CompositionError> {
+    let mut errors = Vec::new();
+    let reachable = reachable_from_roots(query_graph);
+
+    for (type_name, type_def) in &schema.types {
+        let fields = match type_def {
+            ExtendedType::Object(object) => &object.fields,
+            ExtendedType::Interface(interface) => &interface.fields,
+            _ => continue,
+        };
+        let candidate_types = resolving_type_names(schema, type_name);
+
+        for field_name in fields.keys() {
+            if !is_field_reachable(query_graph, &reachable, &candidate_types, field_name) {
+                errors.push(CompositionError::SatisfiabilityError {
+                    message: format!(
+                        "Field \"{type_name}.{field_name}\" cannot be resolved across subgraphs"
+                    ),
+                });
+            }
+        }
+    }
+
+    errors
+}
+
+/// Every node reachable from a federated root, following any transition (field collection, key
+/// resolution, downcasts, subgraph entry, ...), not just direct field edges — a field can only
+/// be resolved if it sits behind a path composition actually assembled across subgraphs.
+fn reachable_from_roots(query_graph: &QueryGraph) -> HashSet<NodeIndex> {
+    let mut visited = HashSet::new();
+    let mut stack: Vec<NodeIndex> = query_graph.root_kinds_to_node.values().copied().collect();
+
+    while let Some(node_index) = stack.pop() {
+        if !visited.insert(node_index) {
+            continue;
+        }
+        for edge in query_graph.graph.edges(node_index) {
+            stack.push(edge.target());
+        }
+    }
+
+    visited
+}
+
+/// `type_name` itself, plus (when it's an interface) every object type that implements it.
+/// Interface fields are resolved through the concrete object nodes the query graph builds for
+/// each implementing type, not through a node typed as the interface itself, so checking only
+/// `type_name` would flag every interface field as unresolvable.
+fn resolving_type_names<'a>(schema: &'a Schema, type_name: &'a Name) -> HashSet<&'a str> {
+    let mut candidates = HashSet::new();
+    candidates.insert(type_name.as_str());
+
+    for (other_name, other_def) in &schema.types {
+        if let ExtendedType::Object(object) = other_def {
+            if object.implements_interfaces.contains(type_name) {
+                candidates.insert(other_name.as_str());
+            }
+        }
+    }
+
+    candidates
+}
+
+fn is_field_reachable(
+    query_graph: &QueryGraph,
+    reachable: &HashSet<NodeIndex>,
+    candidate_types: &HashSet<&str>,
+    field_name: &str,
+) -> bool {
+    query_graph.graph.node_indices().any(|node_index| {
+        if !reachable.contains(&node_index) {
+            return false;
+        }
+        let node = &query_graph.graph[node_index];
+        let QueryGraphNodeType::SchemaType(node_type) = &node.type_ else {
+            return false;
+        };
+        if !candidate_types.contains(node_type.type_name()) {
+            return false;
+        }
+
+        query_graph
+            .graph
+            .edges(node_index)
+            .any(|edge| match &edge.weight().transition {
+                QueryGraphEdgeTransition::FieldCollection {
+                    field_definition_position,
+                    ..
+                } => field_definition_position.field_name() == field_name,
+                _ => false,
+            })
+    })
+}
@@ -2,7 +2,7 @@
 
     use apollo_compiler::Schema;
     use apollo_compiler::validation::Valid;
-    use apollo_federation::{composition::{merge_subgraphs, post_merge_validations, pre_merge_validations}, error::CompositionError, subgraph::typestate::{Subgraph, Validated}, supergraph::{Merged, Supergraph}};
+    use apollo_federation::{composition::{compose, merge_subgraphs, post_merge_validations, pre_merge_validations}, error::CompositionError, subgraph::typestate::{Subgraph, Validated}, supergraph::{Merged, Supergraph}};
 
     fn create_dummy_validated_subgraph() -> Subgraph<Validated> {
         use apollo_compiler::Schema;
@@ -36,6 +36,28 @@
         validated
     }
 
+    fn create_dummy_validated_subgraph_named(name: &str) -> Subgraph<Validated> {
+        use apollo_compiler::Schema;
+        use apollo_federation::{
+            subgraph::typestate::{Initial, Subgraph},
+            composition::upgrade_subgraphs_if_necessary,
+        };
+
+        let raw_sdl = r#"
+            type Query {
+                hello: String
+            }
+        "#;
+
+        let parsed_schema = Schema::parse(raw_sdl, "test.graphql").expect("schema parse failed");
+        let subgraph = Subgraph::<Initial>::new(name, "http://localhost", parsed_schema);
+        let expanded = subgraph.expand_links().expect("expand_links failed");
+        let upgraded_vec = upgrade_subgraphs_if_necessary(vec![expanded]).expect("upgrade failed");
+        let upgraded = upgraded_vec.into_iter().next().expect("no subgraph");
+
+        upgraded.validate().expect("validation failed")
+    }
+
 
     #[allow(dead_code)]
     fn create_dummy_supergraph() -> Supergraph<Merged> {
@@ -109,3 +131,100 @@
         }));
     }
 
+    #[test]
+    fn test_pre_merge_validations_allows_distinct_names_with_identical_sdl() {
+        let subgraph1 = create_dummy_validated_subgraph_named("A");
+        let subgraph2 = create_dummy_validated_subgraph_named("B");
+        let result = pre_merge_validations(&[subgraph1, subgraph2]);
+
+        // Identical SDL is fine as long as the subgraph names themselves are distinct.
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_merge_subgraphs_preserves_distinct_names_with_identical_sdl() {
+        let subgraph1 = create_dummy_validated_subgraph_named("A");
+        let subgraph2 = create_dummy_validated_subgraph_named("B");
+        let result = merge_subgraphs(vec![subgraph1, subgraph2]);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_merge_subgraphs_rejects_duplicate_names_even_without_pre_merge_validations() {
+        let subgraph1 = create_dummy_validated_subgraph_named("Dup");
+        let subgraph2 = create_dummy_validated_subgraph_named("Dup");
+        let result = merge_subgraphs(vec![subgraph1, subgraph2]);
+
+        assert!(result.is_err());
+        let errors = result.unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, CompositionError::TypeDefinitionInvalid { .. })));
+    }
+
+    // Regression fixture for field resolvability: `Product` is an entity split across two
+    // subgraphs, each contributing fields reachable only via the other's `@key`. A
+    // resolvability check that only walks direct root-to-field edges (rather than following
+    // key/requires edges transitively) would wrongly flag one of these fields as unresolvable.
+    #[test]
+    fn test_compose_resolves_entity_fields_contributed_by_multiple_subgraphs() {
+        use apollo_federation::subgraph::typestate::Initial;
+
+        let products_sdl = r#"
+            extend schema
+              @link(url: "https://specs.apollo.dev/federation/v2.3", import: ["@key"])
+
+            type Query {
+                product(id: ID!): Product
+            }
+
+            type Product @key(fields: "id") {
+                id: ID!
+                name: String!
+            }
+        "#;
+        let pricing_sdl = r#"
+            extend schema
+              @link(url: "https://specs.apollo.dev/federation/v2.3", import: ["@key"])
+
+            type Query {
+                _unused: String
+            }
+
+            type Product @key(fields: "id") {
+                id: ID!
+                price: Int!
+            }
+        "#;
+
+        let products_schema =
+            Schema::parse(products_sdl, "products.graphql").expect("schema parse failed");
+        let pricing_schema =
+            Schema::parse(pricing_sdl, "pricing.graphql").expect("schema parse failed");
+
+        let products =
+            Subgraph::<Initial>::new("products", "http://localhost/products", products_schema);
+        let pricing =
+            Subgraph::<Initial>::new("pricing", "http://localhost/pricing", pricing_schema);
+
+        let result = compose(vec![products, pricing]);
+        assert!(result.is_ok(), "composition failed: {:?}", result.err());
+
+        let supergraph = result.unwrap();
+        let product_fields = supergraph
+            .schema()
+            .types
+            .get("Product")
+            .expect("Product type missing from supergraph")
+            .as_object()
+            .expect("Product is not an object type")
+            .fields
+            .keys()
+            .map(|name| name.as_str())
+            .collect::<Vec<_>>();
+
+        assert!(product_fields.contains(&"name"));
+        assert!(product_fields.contains(&"price"));
+    }
+